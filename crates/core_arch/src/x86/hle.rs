@@ -0,0 +1,184 @@
+//! Intel's Hardware Lock Elision (HLE).
+//!
+//! HLE is the other half of Intel's Transactional Synchronization Extensions (TSX); the
+//! restricted transactional memory counterpart lives in the [`rtm`](super::rtm) module.
+//!
+//! Hardware Lock Elision works by prefixing the atomic read-modify-write that *acquires* a lock
+//! with `XACQUIRE` (byte `0xF2`) and the store that *releases* it with `XRELEASE` (byte `0xF3`).
+//! The CPU then executes the critical section speculatively without actually taking the lock,
+//! committing atomically at the release and transparently falling back to real locking on
+//! conflict, so legacy lock-based code gains transactional speedups with no change in logic.
+//!
+//! TSX ships RTM and HLE together, and rustc's x86 feature allowlist does not expose a separate
+//! `hle` feature, so these intrinsics are gated on the `rtm` target feature.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [Wikipedia][wikipedia_hle] provides a quick overview of the assembly prefixes.
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+//! [wikipedia_hle]: https://en.wikipedia.org/wiki/Transactional_Synchronization_Extensions#Hardware_Lock_Elision
+
+use core::arch::asm;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Stores `val` into `*dst` with an `XRELEASE` prefix, marking the end of an elided critical
+/// section.
+///
+/// The prefixed store releases a lock previously acquired with [`_exchange_acquire`] or
+/// [`_compare_exchange_acquire`], committing the speculative region.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xrelease))]
+pub unsafe fn _store_release(dst: *mut u32, val: u32) {
+    asm!(
+        "xrelease mov dword ptr [{dst}], {val:e}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Stores `val` into `*dst` with an `XRELEASE` prefix, marking the end of an elided critical
+/// section.
+///
+/// The prefixed store releases a lock previously acquired with [`_exchange_acquire64`] or
+/// [`_compare_exchange_acquire64`], committing the speculative region.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xrelease))]
+pub unsafe fn _store_release64(dst: *mut u64, val: u64) {
+    asm!(
+        "xrelease mov qword ptr [{dst}], {val}",
+        dst = in(reg) dst,
+        val = in(reg) val,
+        options(nostack, preserves_flags),
+    );
+}
+
+/// Atomically exchanges `val` with `*dst` using an `XACQUIRE`-prefixed `xchg`, returning the
+/// previous value and marking the start of an elided critical section.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xacquire))]
+pub unsafe fn _exchange_acquire(dst: *mut u32, val: u32) -> u32 {
+    let mut prev = val;
+    asm!(
+        "xacquire xchg dword ptr [{dst}], {prev:e}",
+        dst = in(reg) dst,
+        prev = inout(reg) prev,
+        options(nostack, preserves_flags),
+    );
+    prev
+}
+
+/// Atomically exchanges `val` with `*dst` using an `XACQUIRE`-prefixed `xchg`, returning the
+/// previous value and marking the start of an elided critical section.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xacquire))]
+pub unsafe fn _exchange_acquire64(dst: *mut u64, val: u64) -> u64 {
+    let mut prev = val;
+    asm!(
+        "xacquire xchg qword ptr [{dst}], {prev}",
+        dst = in(reg) dst,
+        prev = inout(reg) prev,
+        options(nostack, preserves_flags),
+    );
+    prev
+}
+
+/// Compares `*dst` against `old` and, on a match, stores `new` using an `XACQUIRE`-prefixed
+/// `lock cmpxchg`, returning the value that was read.
+///
+/// The return value equals `old` exactly when the exchange succeeded. A successful exchange marks
+/// the start of an elided critical section.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xacquire))]
+pub unsafe fn _compare_exchange_acquire(dst: *mut u32, old: u32, new: u32) -> u32 {
+    let prev;
+    asm!(
+        "xacquire lock cmpxchg dword ptr [{dst}], {new:e}",
+        dst = in(reg) dst,
+        new = in(reg) new,
+        inout("eax") old => prev,
+        options(nostack),
+    );
+    prev
+}
+
+/// Compares `*dst` against `old` and, on a match, stores `new` using an `XACQUIRE`-prefixed
+/// `lock cmpxchg`, returning the value that was read.
+///
+/// The return value equals `old` exactly when the exchange succeeded. A successful exchange marks
+/// the start of an elided critical section.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xacquire))]
+pub unsafe fn _compare_exchange_acquire64(dst: *mut u64, old: u64, new: u64) -> u64 {
+    let prev;
+    asm!(
+        "xacquire lock cmpxchg qword ptr [{dst}], {new}",
+        dst = in(reg) dst,
+        new = in(reg) new,
+        inout("rax") old => prev,
+        options(nostack),
+    );
+    prev
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use crate::core_arch::x86::*;
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_exchange_acquire_store_release() {
+        let mut lock: u32 = 0;
+        // acquire the (elided) lock
+        let prev = hle::_exchange_acquire(&mut lock, 1);
+        assert_eq!(prev, 0);
+        assert_eq!(lock, 1);
+        // release it again
+        hle::_store_release(&mut lock, 0);
+        assert_eq!(lock, 0);
+    }
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_compare_exchange_acquire() {
+        let mut lock: u32 = 0;
+        // acquiring succeeds while the lock is free and reads back the old value
+        let prev = hle::_compare_exchange_acquire(&mut lock, 0, 1);
+        assert_eq!(prev, 0);
+        assert_eq!(lock, 1);
+        // a second attempt fails and leaves the lock held
+        let prev = hle::_compare_exchange_acquire(&mut lock, 0, 1);
+        assert_eq!(prev, 1);
+        assert_eq!(lock, 1);
+        hle::_store_release(&mut lock, 0);
+    }
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_exchange_acquire_store_release64() {
+        let mut lock: u64 = 0;
+        let prev = hle::_exchange_acquire64(&mut lock, 1);
+        assert_eq!(prev, 0);
+        assert_eq!(lock, 1);
+        hle::_store_release64(&mut lock, 0);
+        assert_eq!(lock, 0);
+    }
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_compare_exchange_acquire64() {
+        let mut lock: u64 = 0;
+        let prev = hle::_compare_exchange_acquire64(&mut lock, 0, 1);
+        assert_eq!(prev, 0);
+        assert_eq!(lock, 1);
+        hle::_store_release64(&mut lock, 0);
+    }
+}
@@ -75,14 +75,11 @@ pub unsafe fn _xend() {
 /// [Intel's documentation](https://software.intel.com/en-us/cpp-compiler-developer-guide-and-reference-xabort).
 #[inline]
 #[target_feature(enable = "rtm")]
-#[cfg_attr(test, assert_instr(xabort))]
-pub unsafe fn _xabort(imm8: u32) {
-    macro_rules! call {
-        ($imm8:expr) => {
-            x86_xabort($imm8)
-        };
-    }
-    constify_imm8!(imm8, call)
+#[cfg_attr(test, assert_instr(xabort, IMM8 = 0x0))]
+#[rustc_legacy_const_generics(0)]
+pub unsafe fn _xabort<const IMM8: u32>() {
+    static_assert!(IMM8: u32 where IMM8 <= 0xFF);
+    x86_xabort(IMM8 as i8)
 }
 
 /// Queries whether the processor is executing in a transactional region identified by restricted
@@ -103,6 +100,143 @@ pub const fn _xabort_code(status: u32) -> u32 {
     (status >> 24) & 0xFF
 }
 
+/// Ergonomic, misuse-resistant wrapper around the bit-packed status returned by [`_xbegin`].
+///
+/// The raw `u32` is still available as the tuple field and can be compared against the
+/// `_XABORT_*` constants directly, but the predicate methods spell out the common cases —
+/// distinguishing a conflict from a capacity abort, or pulling out the explicit abort code — so
+/// callers can tune their retry logic without juggling bit masks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct XBeginStatus(pub u32);
+
+impl XBeginStatus {
+    /// Wraps the value returned by [`_xbegin`].
+    #[inline]
+    pub const fn new(status: u32) -> Self {
+        XBeginStatus(status)
+    }
+
+    /// Returns `true` if the transaction started successfully (`_XBEGIN_STARTED`).
+    #[inline]
+    pub const fn started(self) -> bool {
+        self.0 == _XBEGIN_STARTED
+    }
+
+    /// Returns `true` if the region was explicitly aborted with [`_xabort`] (`_XABORT_EXPLICIT`).
+    #[inline]
+    pub const fn is_explicit(self) -> bool {
+        !self.started() && self.0 & _XABORT_EXPLICIT != 0
+    }
+
+    /// Returns `true` if the abort may be retried (`_XABORT_RETRY`).
+    #[inline]
+    pub const fn is_retry(self) -> bool {
+        !self.started() && self.0 & _XABORT_RETRY != 0
+    }
+
+    /// Returns `true` if the abort was caused by a memory conflict (`_XABORT_CONFLICT`).
+    #[inline]
+    pub const fn is_conflict(self) -> bool {
+        !self.started() && self.0 & _XABORT_CONFLICT != 0
+    }
+
+    /// Returns `true` if the abort was caused by exceeding the transaction's capacity
+    /// (`_XABORT_CAPACITY`).
+    #[inline]
+    pub const fn is_capacity(self) -> bool {
+        !self.started() && self.0 & _XABORT_CAPACITY != 0
+    }
+
+    /// Returns `true` if the abort was caused by a debug trap (`_XABORT_DEBUG`).
+    #[inline]
+    pub const fn is_debug(self) -> bool {
+        !self.started() && self.0 & _XABORT_DEBUG != 0
+    }
+
+    /// Returns `true` if the abort occurred in an inner nested transaction (`_XABORT_NESTED`).
+    #[inline]
+    pub const fn is_nested(self) -> bool {
+        !self.started() && self.0 & _XABORT_NESTED != 0
+    }
+
+    /// Returns the code passed to [`_xabort`], but only when the region was explicitly aborted.
+    #[inline]
+    pub const fn abort_code(self) -> Option<u8> {
+        if self.is_explicit() {
+            Some(_xabort_code(self.0) as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Captures the common "retry only if `_XABORT_RETRY` is set" policy: `true` for an aborted
+    /// status that the processor flagged as retryable.
+    #[inline]
+    pub const fn should_retry(self) -> bool {
+        !self.started() && self.is_retry()
+    }
+}
+
+impl From<u32> for XBeginStatus {
+    #[inline]
+    fn from(status: u32) -> Self {
+        XBeginStatus(status)
+    }
+}
+
+/// The outcome of running a [`transaction`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Transaction<T> {
+    /// The body ran to completion inside a speculative RTM region and committed with `_xend`.
+    Committed(T),
+    /// The transaction could not commit — it either exhausted its retry budget or hit a
+    /// non-retryable abort such as `_XABORT_CAPACITY` — so the fallback closure produced this
+    /// value instead.
+    Fallback(T),
+    /// The body explicitly aborted the region via [`_xabort`]; carries the decoded abort code.
+    Aborted(u8),
+}
+
+/// Runs `body` inside a restricted transactional memory (RTM) region, retrying on transient
+/// aborts and falling back to `fallback` when the region cannot commit.
+///
+/// The body is executed between [`_xbegin`] and [`_xend`]; on commit its result is returned as
+/// [`Transaction::Committed`]. On abort the status is decoded:
+///
+/// * if `_XABORT_RETRY` is set and the `retries` budget is not yet exhausted, the body is retried;
+/// * if `_XABORT_EXPLICIT` is set, the decoded [`_xabort_code`] is returned as
+///   [`Transaction::Aborted`];
+/// * otherwise — a non-retryable abort or an exhausted budget — `fallback` is run and its result
+///   is returned as [`Transaction::Fallback`].
+///
+/// Because the body runs speculatively, any panic or I/O performed inside it is silently rolled
+/// back on abort; keep it side-effect free and push irrevocable work into `fallback`.
+#[inline]
+#[target_feature(enable = "rtm")]
+pub unsafe fn transaction<T>(
+    retries: u32,
+    mut body: impl FnMut() -> T,
+    fallback: impl FnOnce() -> T,
+) -> Transaction<T> {
+    let mut budget = retries;
+    loop {
+        let status = _xbegin();
+        if status == _XBEGIN_STARTED {
+            let result = body();
+            _xend();
+            return Transaction::Committed(result);
+        }
+        if status & _XABORT_RETRY != 0 && budget > 0 {
+            budget -= 1;
+            continue;
+        }
+        if status & _XABORT_EXPLICIT != 0 {
+            return Transaction::Aborted(_xabort_code(status) as u8);
+        }
+        return Transaction::Fallback(fallback());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use stdsimd_test::simd_test;
@@ -126,18 +260,19 @@ mod tests {
 
     #[simd_test(enable = "rtm")]
     unsafe fn test_xabort() {
+        const ABORT_CODE: u32 = 42;
         // aborting outside a transactional region does nothing
-        _xabort(0);
+        _xabort::<ABORT_CODE>();
 
-        for abort_code in 0..10 {
+        for _ in 0..10 {
             let mut x = 0;
             let code = rtm::_xbegin();
             if code == _XBEGIN_STARTED {
                 x += 1;
-                rtm::_xabort(abort_code);
+                rtm::_xabort::<ABORT_CODE>();
             } else if code & _XABORT_EXPLICIT != 0 {
                 let test_abort_code = rtm::_xabort_code(code);
-                assert_eq!(test_abort_code, abort_code);
+                assert_eq!(test_abort_code, ABORT_CODE);
             }
             assert_eq!(x, 0);
         }
@@ -160,4 +295,45 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_transaction_commit() {
+        let mut x = 0;
+        let outcome = rtm::transaction(10, || { x += 1; x }, || -1);
+        // the body runs at least once and commits (possibly after a few retries)
+        assert_eq!(outcome, rtm::Transaction::Committed(x));
+        assert!(x >= 1);
+    }
+
+    #[simd_test(enable = "rtm")]
+    unsafe fn test_transaction_fallback() {
+        // a body that always explicitly aborts with a non-retry code never commits; with a zero
+        // retry budget we either surface the explicit code or fall back.
+        const ABORT_CODE: u32 = 7;
+        let outcome = rtm::transaction(0, || rtm::_xabort::<ABORT_CODE>(), || ());
+        match outcome {
+            rtm::Transaction::Aborted(code) => assert_eq!(code, ABORT_CODE as u8),
+            rtm::Transaction::Fallback(()) => {}
+            rtm::Transaction::Committed(()) => panic!("aborting body must not commit"),
+        }
+    }
+
+    #[test]
+    fn test_xbegin_status_decode() {
+        assert!(rtm::XBeginStatus::new(_XBEGIN_STARTED).started());
+        assert_eq!(rtm::XBeginStatus::new(_XBEGIN_STARTED).abort_code(), None);
+
+        // explicit abort with code 0x42 set in the high byte
+        let status = rtm::XBeginStatus::new((0x42 << 24) | _XABORT_EXPLICIT);
+        assert!(!status.started());
+        assert!(status.is_explicit());
+        assert_eq!(status.abort_code(), Some(0x42));
+        assert!(!status.should_retry());
+
+        let conflict = rtm::XBeginStatus::from(_XABORT_CONFLICT | _XABORT_RETRY);
+        assert!(conflict.is_conflict());
+        assert!(!conflict.is_capacity());
+        assert_eq!(conflict.abort_code(), None);
+        assert!(conflict.should_retry());
+    }
+}